@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    InsufficientFee = 1,
+    NotInitialized = 2,
+    InvalidParameters = 3,
+    TokenNotFound = 4,
+    Unauthorized = 5,
+    AlreadyInitialized = 6,
+    Paused = 7,
+}
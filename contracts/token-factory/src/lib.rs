@@ -0,0 +1,457 @@
+#![no_std]
+
+mod error;
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+use error::FactoryError;
+use storage::{
+    append_creator_token, clear_pending_admin, has_state, read_creator_tokens, read_pending_admin,
+    read_state, read_token_count, read_token_index_by_address, read_token_info,
+    read_vesting_claimed, read_vesting_schedule, write_pending_admin, write_state,
+    write_token_count, write_token_index_by_address, write_token_info, write_vesting_claimed,
+    write_vesting_schedule, State, TokenInfo, VestingTranche,
+};
+
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short, token::Client as FeeTokenClient,
+    Address, BytesN, Env, String, Vec,
+};
+
+// Pulls in the compiled `nova-token` contract so it can be deployed fresh for
+// every `create_token` call. Build `contracts/token` to wasm32 first:
+//   cargo build -p nova-token --target wasm32-unknown-unknown --release
+mod token_contract {
+    soroban_sdk::contractimport!(
+        file = "../token/target/wasm32-unknown-unknown/release/nova_token.wasm"
+    );
+}
+
+/// Panics with `NotInitialized` if `initialize` hasn't been called yet,
+/// instead of letting storage reads fail with a raw unwrap panic.
+fn require_initialized(env: &Env) {
+    if !has_state(env) {
+        panic_with_error!(env, FactoryError::NotInitialized);
+    }
+}
+
+/// Deploys a fresh standard-interface token and records it in the registry.
+/// `initial_supply` is split between `mint_to`, which receives
+/// `initial_supply - escrow_amount` directly, and the factory contract
+/// itself, which is minted `escrow_amount` to hold in escrow for vesting
+/// (pass `0` when there's no vesting schedule). Shared by `create_token`
+/// and `create_token_with_vesting`, which only differ in that split.
+#[allow(clippy::too_many_arguments)]
+fn deploy_token(
+    env: &Env,
+    creator: &Address,
+    name: String,
+    symbol: String,
+    decimals: u32,
+    initial_supply: i128,
+    metadata_uri: Option<String>,
+    max_supply: Option<i128>,
+    mint_to: &Address,
+    escrow_amount: i128,
+) -> (Address, u32) {
+    let index = read_token_count(env);
+
+    let mut salt_bytes = [0u8; 32];
+    salt_bytes[28..32].copy_from_slice(&index.to_be_bytes());
+    let salt = BytesN::from_array(env, &salt_bytes);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(token_contract::WASM);
+    let token_address = env.deployer().with_current_contract(salt).deploy(wasm_hash);
+
+    let token_client = token_contract::Client::new(env, &token_address);
+    token_client.initialize(creator, &decimals, &name, &symbol, &max_supply);
+
+    let direct_amount = initial_supply - escrow_amount;
+    if direct_amount > 0 {
+        token_client.mint(mint_to, &direct_amount);
+    }
+    if escrow_amount > 0 {
+        token_client.mint(&env.current_contract_address(), &escrow_amount);
+    }
+
+    write_token_info(
+        env,
+        index,
+        &TokenInfo {
+            address: token_address.clone(),
+            creator: creator.clone(),
+            name,
+            symbol: symbol.clone(),
+            decimals,
+            total_supply: initial_supply,
+            metadata_uri,
+        },
+    );
+    write_token_count(env, index + 1);
+    append_creator_token(env, creator, index);
+    write_token_index_by_address(env, &token_address, index);
+
+    env.events().publish(
+        (symbol_short!("factory"), symbol_short!("created")),
+        (
+            index,
+            token_address.clone(),
+            creator.clone(),
+            symbol,
+            initial_supply,
+        ),
+    );
+
+    (token_address, index)
+}
+
+#[contract]
+pub struct TokenFactory;
+
+#[contractimpl]
+impl TokenFactory {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        fee_token: Address,
+        base_fee: i128,
+        metadata_fee: i128,
+    ) {
+        if has_state(&env) {
+            panic_with_error!(env, FactoryError::AlreadyInitialized);
+        }
+
+        write_state(
+            &env,
+            &State {
+                admin: admin.clone(),
+                treasury: treasury.clone(),
+                fee_token,
+                base_fee,
+                metadata_fee,
+                paused: false,
+            },
+        );
+        write_token_count(&env, 0);
+
+        env.events().publish(
+            (symbol_short!("factory"), symbol_short!("init")),
+            (admin, treasury, base_fee, metadata_fee),
+        );
+    }
+
+    pub fn get_state(env: Env) -> State {
+        require_initialized(&env);
+        read_state(&env)
+    }
+
+    /// Starts a two-step admin rotation: the new admin must call
+    /// `accept_admin` themselves before the change takes effect, so a typo'd
+    /// address can never brick the contract.
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) {
+        require_initialized(&env);
+        let state = read_state(&env);
+        if admin != state.admin {
+            panic_with_error!(env, FactoryError::Unauthorized);
+        }
+        admin.require_auth();
+
+        write_pending_admin(&env, &new_admin);
+    }
+
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        require_initialized(&env);
+        new_admin.require_auth();
+
+        let pending = match read_pending_admin(&env) {
+            Some(pending) => pending,
+            None => panic_with_error!(env, FactoryError::Unauthorized),
+        };
+        if new_admin != pending {
+            panic_with_error!(env, FactoryError::Unauthorized);
+        }
+
+        let mut state = read_state(&env);
+        state.admin = new_admin;
+        write_state(&env, &state);
+        clear_pending_admin(&env);
+    }
+
+    /// Kill-switch: while paused, `create_token` rejects before collecting
+    /// any fee. Existing registry reads stay available.
+    pub fn set_paused(env: Env, admin: Address, paused: bool) {
+        require_initialized(&env);
+        let mut state = read_state(&env);
+        if admin != state.admin {
+            panic_with_error!(env, FactoryError::Unauthorized);
+        }
+        admin.require_auth();
+
+        state.paused = paused;
+        write_state(&env, &state);
+    }
+
+    pub fn paused(env: Env) -> bool {
+        require_initialized(&env);
+        read_state(&env).paused
+    }
+
+    pub fn update_fees(
+        env: Env,
+        admin: Address,
+        base_fee: Option<i128>,
+        metadata_fee: Option<i128>,
+    ) {
+        require_initialized(&env);
+        let mut state = read_state(&env);
+        if admin != state.admin {
+            panic_with_error!(env, FactoryError::Unauthorized);
+        }
+        admin.require_auth();
+
+        let old_base_fee = state.base_fee;
+        let old_metadata_fee = state.metadata_fee;
+
+        if let Some(base_fee) = base_fee {
+            state.base_fee = base_fee;
+        }
+        if let Some(metadata_fee) = metadata_fee {
+            state.metadata_fee = metadata_fee;
+        }
+        write_state(&env, &state);
+
+        env.events().publish(
+            (symbol_short!("factory"), symbol_short!("fees")),
+            (
+                old_base_fee,
+                state.base_fee,
+                old_metadata_fee,
+                state.metadata_fee,
+            ),
+        );
+    }
+
+    /// Deploys a fresh standard-interface token, mints `initial_supply` to
+    /// `creator`, and records it in the registry.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_token(
+        env: Env,
+        creator: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+        metadata_uri: Option<String>,
+        fee: i128,
+        max_supply: Option<i128>,
+    ) -> Address {
+        require_initialized(&env);
+        creator.require_auth();
+
+        if name.is_empty() || symbol.is_empty() {
+            panic_with_error!(env, FactoryError::InvalidParameters);
+        }
+
+        let state = read_state(&env);
+        if state.paused {
+            panic_with_error!(env, FactoryError::Paused);
+        }
+
+        let required_fee = if metadata_uri.is_some() {
+            state.base_fee + state.metadata_fee
+        } else {
+            state.base_fee
+        };
+        if fee < required_fee {
+            panic_with_error!(env, FactoryError::InsufficientFee);
+        }
+
+        let (token_address, _index) = deploy_token(
+            &env,
+            &creator,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            metadata_uri,
+            max_supply,
+            &creator,
+            0,
+        );
+
+        FeeTokenClient::new(&env, &state.fee_token).transfer(
+            &creator,
+            &state.treasury,
+            &required_fee,
+        );
+
+        env.events().publish(
+            (symbol_short!("factory"), symbol_short!("feepaid")),
+            (creator, state.treasury, required_fee),
+        );
+
+        token_address
+    }
+
+    /// Same as `create_token`, but `schedule`'s total is minted to the
+    /// factory itself, which acts as an escrow releasing those tranches to
+    /// `creator` over time via `claim`. Any part of `initial_supply` left
+    /// unscheduled is minted straight to `creator` up front.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_token_with_vesting(
+        env: Env,
+        creator: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+        metadata_uri: Option<String>,
+        fee: i128,
+        max_supply: Option<i128>,
+        schedule: Vec<VestingTranche>,
+    ) -> Address {
+        require_initialized(&env);
+        creator.require_auth();
+
+        if name.is_empty() || symbol.is_empty() {
+            panic_with_error!(env, FactoryError::InvalidParameters);
+        }
+
+        let state = read_state(&env);
+        if state.paused {
+            panic_with_error!(env, FactoryError::Paused);
+        }
+
+        let required_fee = if metadata_uri.is_some() {
+            state.base_fee + state.metadata_fee
+        } else {
+            state.base_fee
+        };
+        if fee < required_fee {
+            panic_with_error!(env, FactoryError::InsufficientFee);
+        }
+
+        if schedule.iter().any(|tranche| tranche.amount < 0) {
+            panic_with_error!(env, FactoryError::InvalidParameters);
+        }
+        let scheduled_total: i128 = schedule.iter().map(|tranche| tranche.amount).sum();
+        if scheduled_total > initial_supply {
+            panic_with_error!(env, FactoryError::InvalidParameters);
+        }
+
+        let (token_address, index) = deploy_token(
+            &env,
+            &creator,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            metadata_uri,
+            max_supply,
+            &creator,
+            scheduled_total,
+        );
+
+        write_vesting_schedule(&env, index, &schedule);
+        write_vesting_claimed(&env, index, 0);
+
+        FeeTokenClient::new(&env, &state.fee_token).transfer(
+            &creator,
+            &state.treasury,
+            &required_fee,
+        );
+
+        env.events().publish(
+            (symbol_short!("factory"), symbol_short!("feepaid")),
+            (creator, state.treasury, required_fee),
+        );
+
+        token_address
+    }
+
+    /// Releases whatever vested tranches of `token_index` have unlocked
+    /// since the last claim, to that token's creator.
+    pub fn claim(env: Env, token_index: u32) {
+        let info = match read_token_info(&env, token_index) {
+            Some(info) => info,
+            None => panic_with_error!(env, FactoryError::TokenNotFound),
+        };
+        info.creator.require_auth();
+
+        let schedule = match read_vesting_schedule(&env, token_index) {
+            Some(schedule) => schedule,
+            None => panic_with_error!(env, FactoryError::TokenNotFound),
+        };
+        let claimed = read_vesting_claimed(&env, token_index);
+
+        let current_ledger = env.ledger().sequence();
+        let unlocked: i128 = schedule
+            .iter()
+            .filter(|tranche| tranche.unlock_ledger <= current_ledger)
+            .map(|tranche| tranche.amount)
+            .sum();
+
+        let releasable = unlocked - claimed;
+        if releasable <= 0 {
+            return;
+        }
+
+        let token_client = token_contract::Client::new(&env, &info.address);
+        token_client.transfer(&env.current_contract_address(), &info.creator, &releasable);
+
+        write_vesting_claimed(&env, token_index, claimed + releasable);
+    }
+
+    pub fn get_token_count(env: Env) -> u32 {
+        read_token_count(&env)
+    }
+
+    pub fn get_token_info(env: Env, index: u32) -> Option<TokenInfo> {
+        read_token_info(&env, index)
+    }
+
+    /// Pages through the registry in creation order, `limit` capped at 100.
+    pub fn get_tokens(env: Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+        if limit > 100 {
+            panic_with_error!(env, FactoryError::InvalidParameters);
+        }
+
+        let count = read_token_count(&env);
+        let end = start.saturating_add(limit);
+        let mut tokens = Vec::new(&env);
+        let mut index = start;
+        while index < count && index < end {
+            if let Some(info) = read_token_info(&env, index) {
+                tokens.push_back(info);
+            }
+            index += 1;
+        }
+        tokens
+    }
+
+    pub fn get_tokens_by_creator(env: Env, creator: Address) -> Vec<u32> {
+        read_creator_tokens(&env, &creator)
+    }
+
+    pub fn get_token_by_address(env: Env, addr: Address) -> Option<TokenInfo> {
+        read_token_index_by_address(&env, &addr).and_then(|index| read_token_info(&env, index))
+    }
+
+    /// Refreshes the registry's cached `total_supply` for a token from its
+    /// own ledger state, so callers see the effect of mints/burns that
+    /// happened after `create_token`.
+    pub fn sync_supply(env: Env, index: u32) {
+        let mut info = match read_token_info(&env, index) {
+            Some(info) => info,
+            None => panic_with_error!(env, FactoryError::TokenNotFound),
+        };
+
+        let token_client = token_contract::Client::new(&env, &info.address);
+        info.total_supply = token_client.total_supply();
+        write_token_info(&env, index, &info);
+    }
+}
@@ -0,0 +1,145 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    State,
+    TokenCount,
+    TokenInfo(u32),
+    CreatorTokens(Address),
+    TokenIndexByAddress(Address),
+    PendingAdmin,
+    VestingSchedule(u32),
+    VestingClaimed(u32),
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct State {
+    pub admin: Address,
+    pub treasury: Address,
+    pub fee_token: Address,
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+    pub paused: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct TokenInfo {
+    pub address: Address,
+    pub creator: Address,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub total_supply: i128,
+    pub metadata_uri: Option<String>,
+}
+
+/// One unlock point in a vesting schedule: `amount` becomes claimable once
+/// the ledger sequence reaches `unlock_ledger`.
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingTranche {
+    pub unlock_ledger: u32,
+    pub amount: i128,
+}
+
+pub fn has_state(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::State)
+}
+
+pub fn read_state(env: &Env) -> State {
+    env.storage().instance().get(&DataKey::State).unwrap()
+}
+
+pub fn write_state(env: &Env, state: &State) {
+    env.storage().instance().set(&DataKey::State, state);
+}
+
+pub fn read_token_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenCount)
+        .unwrap_or(0)
+}
+
+pub fn write_token_count(env: &Env, count: u32) {
+    env.storage().instance().set(&DataKey::TokenCount, &count);
+}
+
+pub fn read_token_info(env: &Env, index: u32) -> Option<TokenInfo> {
+    env.storage().persistent().get(&DataKey::TokenInfo(index))
+}
+
+pub fn write_token_info(env: &Env, index: u32, info: &TokenInfo) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenInfo(index), info);
+}
+
+pub fn read_creator_tokens(env: &Env, creator: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorTokens(creator.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn append_creator_token(env: &Env, creator: &Address, index: u32) {
+    let mut tokens = read_creator_tokens(env, creator);
+    tokens.push_back(index);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorTokens(creator.clone()), &tokens);
+}
+
+pub fn read_token_index_by_address(env: &Env, address: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenIndexByAddress(address.clone()))
+}
+
+pub fn write_token_index_by_address(env: &Env, address: &Address, index: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenIndexByAddress(address.clone()), &index);
+}
+
+pub fn read_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn write_pending_admin(env: &Env, pending: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending);
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn read_vesting_schedule(env: &Env, index: u32) -> Option<Vec<VestingTranche>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VestingSchedule(index))
+}
+
+pub fn write_vesting_schedule(env: &Env, index: u32, schedule: &Vec<VestingTranche>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VestingSchedule(index), schedule);
+}
+
+pub fn read_vesting_claimed(env: &Env, index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VestingClaimed(index))
+        .unwrap_or(0)
+}
+
+pub fn write_vesting_claimed(env: &Env, index: u32, claimed: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VestingClaimed(index), &claimed);
+}
@@ -1,6 +1,18 @@
 use super::*;
-use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
-use soroban_sdk::{Address, Env, String, symbol_short};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{vec, Address, Env, IntoVal, String, TryFromVal};
+
+/// Registers a Stellar Asset Contract to stand in as the factory's fee
+/// token, minting `amount` to `payer` so `create_token` has something real
+/// to transfer.
+fn setup_fee_token(env: &Env, sac_admin: &Address, payer: &Address, amount: i128) -> Address {
+    let fee_token = env
+        .register_stellar_asset_contract_v2(sac_admin.clone())
+        .address();
+    StellarAssetClient::new(env, &fee_token).mint(payer, &amount);
+    fee_token
+}
 
 #[test]
 fn test_initialize() {
@@ -10,20 +22,46 @@ fn test_initialize() {
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
     let base_fee = 70_000_000; // 7 XLM in stroops
     let metadata_fee = 30_000_000; // 3 XLM in stroops
 
     // Initialize factory
-    client.initialize(&admin, &treasury, &base_fee, &metadata_fee);
+    client.initialize(&admin, &treasury, &fee_token, &base_fee, &metadata_fee);
 
     // Verify state
     let state = client.get_state();
     assert_eq!(state.admin, admin);
     assert_eq!(state.treasury, treasury);
+    assert_eq!(state.fee_token, fee_token);
     assert_eq!(state.base_fee, base_fee);
     assert_eq!(state.metadata_fee, metadata_fee);
 }
 
+#[test]
+fn test_initialize_emits_event() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let base_fee = 70_000_000;
+    let metadata_fee = 30_000_000;
+
+    client.initialize(&admin, &treasury, &fee_token, &base_fee, &metadata_fee);
+
+    let events = env.events().all();
+    let (topics, data) = (events.last().unwrap().1, events.last().unwrap().2);
+    assert_eq!(
+        topics,
+        (symbol_short!("factory"), symbol_short!("init")).into_val(&env)
+    );
+    let decoded: (Address, Address, i128, i128) = TryFromVal::try_from_val(&env, &data).unwrap();
+    assert_eq!(decoded, (admin, treasury, base_fee, metadata_fee));
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 fn test_cannot_initialize_twice() {
@@ -33,23 +71,25 @@ fn test_cannot_initialize_twice() {
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
 
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
 }
 
 #[test]
 fn test_update_fees() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, TokenFactory);
     let client = TokenFactoryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
 
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
 
     // Update base fee
     client.update_fees(&admin, &Some(100_000_000), &None);
@@ -63,11 +103,35 @@ fn test_update_fees() {
 }
 
 #[test]
-#[ignore] // Remove this attribute once create_token function is implemented
+fn test_update_fees_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+    client.update_fees(&admin, &Some(100_000_000), &None);
+
+    let events = env.events().all();
+    let (topics, data) = (events.last().unwrap().1, events.last().unwrap().2);
+    assert_eq!(
+        topics,
+        (symbol_short!("factory"), symbol_short!("fees")).into_val(&env)
+    );
+    let decoded: (i128, i128, i128, i128) = TryFromVal::try_from_val(&env, &data).unwrap();
+    assert_eq!(decoded, (70_000_000, 100_000_000, 30_000_000, 30_000_000));
+}
+
+#[test]
 fn test_create_token() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, TokenFactory);
     let client = TokenFactoryClient::new(&env, &contract_id);
 
@@ -77,17 +141,17 @@ fn test_create_token() {
     let creator = Address::generate(&env);
     let base_fee = 70_000_000;
     let metadata_fee = 30_000_000;
+    let expected_fee = base_fee + metadata_fee;
+    let fee_token = setup_fee_token(&env, &admin, &creator, expected_fee);
 
-    client.initialize(&admin, &treasury, &base_fee, &metadata_fee);
+    client.initialize(&admin, &treasury, &fee_token, &base_fee, &metadata_fee);
 
     let name = String::from_str(&env, "Test Token");
     let symbol = String::from_str(&env, "TEST");
     let decimals = 7u32;
-    let initial_supply = 1_000_000_0000000i128;
+    let initial_supply = 10_000_000_000_000i128;
     let metadata_uri = Some(String::from_str(&env, "ipfs://QmTest123"));
-    let expected_fee = base_fee + metadata_fee;
 
-    // TODO: Uncomment once create_token is implemented
     let token_address = client.create_token(
         &creator,
         &name,
@@ -96,6 +160,7 @@ fn test_create_token() {
         &initial_supply,
         &metadata_uri,
         &expected_fee,
+        &None,
     );
 
     // Verify token registered in factory
@@ -112,15 +177,76 @@ fn test_create_token() {
     assert_eq!(token_info.total_supply, initial_supply);
     assert_eq!(token_info.metadata_uri, metadata_uri);
 
-    // Verify initial supply minted (Commented verification)
-    // let token_client = token::Client::new(&env, &token_address);
-    // let creator_balance = token_client.balance(&creator);
-    // assert_eq!(creator_balance, initial_supply);
+    // Verify initial supply minted
+    let token_client = token_contract::Client::new(&env, &token_address);
+    let creator_balance = token_client.balance(&creator);
+    assert_eq!(creator_balance, initial_supply);
+
+    // Verify the fee actually moved from creator to treasury.
+    let fee_token_client = FeeTokenClient::new(&env, &fee_token);
+    assert_eq!(fee_token_client.balance(&creator), 0);
+    assert_eq!(fee_token_client.balance(&treasury), expected_fee);
 }
 
 #[test]
-#[ignore]
-#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")] 
+fn test_create_token_emits_created_and_fee_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let base_fee = 70_000_000;
+    let metadata_fee = 30_000_000;
+    let expected_fee = base_fee + metadata_fee;
+    let fee_token = setup_fee_token(&env, &admin, &creator, expected_fee);
+
+    client.initialize(&admin, &treasury, &fee_token, &base_fee, &metadata_fee);
+
+    let name = String::from_str(&env, "Test Token");
+    let symbol = String::from_str(&env, "TEST");
+    let initial_supply = 10_000_000_000_000i128;
+
+    let token_address = client.create_token(
+        &creator,
+        &name,
+        &symbol,
+        &7,
+        &initial_supply,
+        &Some(String::from_str(&env, "ipfs://QmTest123")),
+        &expected_fee,
+        &None,
+    );
+
+    let events = env.events().all();
+    assert_eq!(events.len(), 3); // initialize, created, fee_collected
+
+    let (created_topics, created_data) = (events.get(1).unwrap().1, events.get(1).unwrap().2);
+    assert_eq!(
+        created_topics,
+        (symbol_short!("factory"), symbol_short!("created")).into_val(&env)
+    );
+    let created_decoded: (u32, Address, Address, String, i128) =
+        TryFromVal::try_from_val(&env, &created_data).unwrap();
+    assert_eq!(
+        created_decoded,
+        (0u32, token_address, creator.clone(), symbol, initial_supply)
+    );
+
+    let (fee_topics, fee_data) = (events.get(2).unwrap().1, events.get(2).unwrap().2);
+    assert_eq!(
+        fee_topics,
+        (symbol_short!("factory"), symbol_short!("feepaid")).into_val(&env)
+    );
+    let fee_decoded: (Address, Address, i128) = TryFromVal::try_from_val(&env, &fee_data).unwrap();
+    assert_eq!(fee_decoded, (creator, treasury, expected_fee));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
 fn test_unauthorized_minting_fails() {
     let env = Env::default();
     let contract_id = env.register_contract(None, TokenFactory);
@@ -128,9 +254,10 @@ fn test_unauthorized_minting_fails() {
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
     let attacker = Address::generate(&env);
 
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
 
     // Attacker tries to mint/create without authorization
     client.create_token(
@@ -141,24 +268,25 @@ fn test_unauthorized_minting_fails() {
         &1000,
         &None,
         &0,
+        &None,
     );
 }
 
 #[test]
-#[ignore]
 #[should_panic(expected = "Error(Contract, #1)")] // InsufficientFee error
 fn test_create_token_insufficient_fee() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, TokenFactory);
     let client = TokenFactoryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
     let creator = Address::generate(&env);
 
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
 
     let name = String::from_str(&env, "Test Token");
     let symbol = String::from_str(&env, "TEST");
@@ -173,24 +301,25 @@ fn test_create_token_insufficient_fee() {
         &1_000_000,
         &metadata_uri,
         &50_000_000,
+        &None,
     );
 }
 
 #[test]
-#[ignore]
 #[should_panic(expected = "Error(Contract, #3)")] // InvalidParameters error
 fn test_create_token_invalid_parameters() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, TokenFactory);
     let client = TokenFactoryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
     let creator = Address::generate(&env);
 
-    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
 
     client.create_token(
         &creator,
@@ -200,5 +329,489 @@ fn test_create_token_invalid_parameters() {
         &1_000_000,
         &None,
         &70_000_000,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // SupplyCapExceeded error
+fn test_create_token_rejects_initial_supply_over_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    client.create_token(
+        &creator,
+        &String::from_str(&env, "Capped Token"),
+        &String::from_str(&env, "CAP"),
+        &7,
+        &1_000_000,
+        &None,
+        &70_000_000,
+        &Some(999_999), // below initial_supply
+    );
+}
+
+#[test]
+fn test_sync_supply_reflects_mint_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fee_token = setup_fee_token(&env, &admin, &creator, 70_000_000);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    let initial_supply = 1_000_000i128;
+    let token_address = client.create_token(
+        &creator,
+        &String::from_str(&env, "Mintable Token"),
+        &String::from_str(&env, "MINT"),
+        &7,
+        &initial_supply,
+        &None,
+        &70_000_000,
+        &Some(10_000_000),
+    );
+
+    let token_client = token_contract::Client::new(&env, &token_address);
+    token_client.mint(&creator, &500_000);
+    token_client.burn(&creator, &200_000);
+
+    client.sync_supply(&0);
+
+    let token_info = client.get_token_info(&0).unwrap();
+    assert_eq!(token_info.total_supply, initial_supply + 500_000 - 200_000);
+    assert_eq!(
+        token_client.total_supply(),
+        initial_supply + 500_000 - 200_000
+    );
+}
+
+#[test]
+fn test_get_tokens_paginates_in_creation_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fee_token = setup_fee_token(&env, &admin, &creator, 3 * 70_000_000);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    for i in 0..3 {
+        client.create_token(
+            &creator,
+            &String::from_str(&env, "Token"),
+            &String::from_str(&env, "TKN"),
+            &7,
+            &(1_000 * (i + 1) as i128),
+            &None,
+            &70_000_000,
+            &None,
+        );
+    }
+
+    let page = client.get_tokens(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().total_supply, 2_000);
+    assert_eq!(page.get(1).unwrap().total_supply, 3_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")] // InvalidParameters error
+fn test_get_tokens_rejects_oversized_limit() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    client.get_tokens(&0, &101);
+}
+
+#[test]
+fn test_get_tokens_handles_start_near_u32_max_without_overflow() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    let page = client.get_tokens(&(u32::MAX - 1), &10);
+    assert_eq!(page.len(), 0);
+}
+
+#[test]
+fn test_get_tokens_by_creator_and_by_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator_a = Address::generate(&env);
+    let creator_b = Address::generate(&env);
+    let fee_token = setup_fee_token(&env, &admin, &creator_a, 2 * 70_000_000);
+    StellarAssetClient::new(&env, &fee_token).mint(&creator_b, &70_000_000);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    let token_a0 = client.create_token(
+        &creator_a,
+        &String::from_str(&env, "A0"),
+        &String::from_str(&env, "A0"),
+        &7,
+        &1_000,
+        &None,
+        &70_000_000,
+        &None,
+    );
+    client.create_token(
+        &creator_b,
+        &String::from_str(&env, "B0"),
+        &String::from_str(&env, "B0"),
+        &7,
+        &1_000,
+        &None,
+        &70_000_000,
+        &None,
+    );
+    client.create_token(
+        &creator_a,
+        &String::from_str(&env, "A1"),
+        &String::from_str(&env, "A1"),
+        &7,
+        &1_000,
+        &None,
+        &70_000_000,
+        &None,
+    );
+
+    let creator_a_tokens = client.get_tokens_by_creator(&creator_a);
+    assert_eq!(creator_a_tokens.len(), 2);
+    assert_eq!(creator_a_tokens.get(0).unwrap(), 0);
+    assert_eq!(creator_a_tokens.get(1).unwrap(), 2);
+
+    let by_address = client.get_token_by_address(&token_a0).unwrap();
+    assert_eq!(by_address.creator, creator_a);
+    assert_eq!(by_address.name, String::from_str(&env, "A0"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")] // Unauthorized error
+fn test_propose_admin_rejects_non_admin_caller() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    client.propose_admin(&attacker, &new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")] // Unauthorized error
+fn test_accept_admin_rejects_wrong_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let impostor = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+    client.propose_admin(&admin, &new_admin);
+
+    client.accept_admin(&impostor);
+}
+
+#[test]
+fn test_accept_admin_rotates_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+    client.propose_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    assert_eq!(client.get_state().admin, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")] // Paused error
+fn test_create_token_rejects_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+    client.set_paused(&admin, &true);
+    assert!(client.paused());
+
+    client.create_token(
+        &creator,
+        &String::from_str(&env, "Blocked"),
+        &String::from_str(&env, "BLK"),
+        &7,
+        &1_000,
+        &None,
+        &70_000_000,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")] // InvalidParameters error
+fn test_create_token_with_vesting_rejects_oversized_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    let schedule = vec![
+        &env,
+        VestingTranche {
+            unlock_ledger: 100,
+            amount: 600_000,
+        },
+        VestingTranche {
+            unlock_ledger: 200,
+            amount: 500_000,
+        },
+    ];
+
+    client.create_token_with_vesting(
+        &creator,
+        &String::from_str(&env, "Vested"),
+        &String::from_str(&env, "VST"),
+        &7,
+        &1_000_000,
+        &None,
+        &70_000_000,
+        &None,
+        &schedule,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")] // InvalidParameters error
+fn test_create_token_with_vesting_rejects_negative_tranche_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee_token = Address::generate(&env);
+    let creator = Address::generate(&env);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    // A negative tranche would otherwise let a creator inflate their own
+    // direct mint past `initial_supply` by masking it as a "negative escrow".
+    let schedule = vec![
+        &env,
+        VestingTranche {
+            unlock_ledger: 100,
+            amount: -500,
+        },
+    ];
+
+    client.create_token_with_vesting(
+        &creator,
+        &String::from_str(&env, "Vested"),
+        &String::from_str(&env, "VST"),
+        &7,
+        &1_000,
+        &None,
+        &70_000_000,
+        &None,
+        &schedule,
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_claim_releases_only_unlocked_tranches_and_prevents_double_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(50);
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fee_token = setup_fee_token(&env, &admin, &creator, 70_000_000);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    let schedule = vec![
+        &env,
+        VestingTranche {
+            unlock_ledger: 100,
+            amount: 400_000,
+        },
+        VestingTranche {
+            unlock_ledger: 200,
+            amount: 600_000,
+        },
+    ];
+
+    let token_address = client.create_token_with_vesting(
+        &creator,
+        &String::from_str(&env, "Vested"),
+        &String::from_str(&env, "VST"),
+        &7,
+        &1_000_000,
+        &None,
+        &70_000_000,
+        &None,
+        &schedule,
+    );
+    let token_client = token_contract::Client::new(&env, &token_address);
+
+    // Nothing unlocked yet.
+    client.claim(&0);
+    assert_eq!(token_client.balance(&creator), 0);
+
+    // First tranche unlocks.
+    env.ledger().set_sequence_number(100);
+    client.claim(&0);
+    assert_eq!(token_client.balance(&creator), 400_000);
+
+    // Re-claiming before the next unlock releases nothing further.
+    client.claim(&0);
+    assert_eq!(token_client.balance(&creator), 400_000);
+
+    // Second tranche unlocks.
+    env.ledger().set_sequence_number(200);
+    client.claim(&0);
+    assert_eq!(token_client.balance(&creator), 1_000_000);
+}
+
+#[test]
+fn test_create_token_with_vesting_mints_unscheduled_remainder_to_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let fee_token = setup_fee_token(&env, &admin, &creator, 70_000_000);
+
+    client.initialize(&admin, &treasury, &fee_token, &70_000_000, &30_000_000);
+
+    // Only 400_000 of the 1_000_000 minted supply is scheduled; the rest
+    // should land in the creator's wallet immediately, not be stranded in
+    // the factory's escrow balance.
+    let schedule = vec![
+        &env,
+        VestingTranche {
+            unlock_ledger: 100,
+            amount: 400_000,
+        },
+    ];
+
+    let token_address = client.create_token_with_vesting(
+        &creator,
+        &String::from_str(&env, "Partially Vested"),
+        &String::from_str(&env, "PVT"),
+        &7,
+        &1_000_000,
+        &None,
+        &70_000_000,
+        &None,
+        &schedule,
+    );
+    let token_client = token_contract::Client::new(&env, &token_address);
+
+    assert_eq!(token_client.balance(&creator), 600_000);
+    assert_eq!(token_client.balance(&contract_id), 400_000);
+
+    env.ledger().set_sequence_number(100);
+    client.claim(&0);
+    assert_eq!(token_client.balance(&creator), 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // NotInitialized error
+fn test_calling_before_initialize_fails() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, TokenFactory);
+    let client = TokenFactoryClient::new(&env, &contract_id);
+
+    client.get_state();
+}
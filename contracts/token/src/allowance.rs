@@ -0,0 +1,20 @@
+use crate::storage_types::{AllowanceDataKey, DataKey};
+use soroban_sdk::{Address, Env};
+
+pub fn read_allowance(env: &Env, from: Address, spender: Address) -> i128 {
+    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    env.storage().temporary().get(&key).unwrap_or(0)
+}
+
+pub fn write_allowance(env: &Env, from: Address, spender: Address, amount: i128) {
+    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    env.storage().temporary().set(&key, &amount);
+}
+
+pub fn spend_allowance(env: &Env, from: Address, spender: Address, amount: i128) {
+    let allowance = read_allowance(env, from.clone(), spender.clone());
+    if allowance < amount {
+        panic!("insufficient allowance");
+    }
+    write_allowance(env, from, spender, allowance - amount);
+}
@@ -0,0 +1,25 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::{Address, Env};
+
+pub fn read_balance(env: &Env, addr: Address) -> i128 {
+    let key = DataKey::Balance(addr);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn write_balance(env: &Env, addr: Address, amount: i128) {
+    let key = DataKey::Balance(addr);
+    env.storage().persistent().set(&key, &amount);
+}
+
+pub fn receive_balance(env: &Env, addr: Address, amount: i128) {
+    let balance = read_balance(env, addr.clone());
+    write_balance(env, addr, balance + amount);
+}
+
+pub fn spend_balance(env: &Env, addr: Address, amount: i128) {
+    let balance = read_balance(env, addr.clone());
+    if balance < amount {
+        panic!("insufficient balance");
+    }
+    write_balance(env, addr, balance - amount);
+}
@@ -0,0 +1,114 @@
+use crate::admin::{has_administrator, read_administrator, write_administrator};
+use crate::allowance::{read_allowance, spend_allowance, write_allowance};
+use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::error::TokenError;
+use crate::metadata::{
+    read_decimal, read_name, read_symbol, write_decimal, write_name, write_symbol,
+};
+use crate::supply::{
+    decrement_supply, increment_supply, read_max_supply, read_supply, write_max_supply,
+};
+use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, String};
+
+fn check_nonnegative_amount(amount: i128) {
+    if amount < 0 {
+        panic!("negative amount is not allowed");
+    }
+}
+
+/// Minimal Soroban Token Interface implementation deployed by `TokenFactory`
+/// for every token it creates.
+#[contract]
+pub struct Token;
+
+#[contractimpl]
+impl Token {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        decimals: u32,
+        name: String,
+        symbol: String,
+        max_supply: Option<i128>,
+    ) {
+        if has_administrator(&env) {
+            panic_with_error!(env, TokenError::AlreadyInitialized);
+        }
+        write_administrator(&env, &admin);
+        write_decimal(&env, decimals);
+        write_name(&env, &name);
+        write_symbol(&env, &symbol);
+        write_max_supply(&env, max_supply);
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = read_administrator(&env);
+        admin.require_auth();
+
+        if let Some(max_supply) = read_max_supply(&env) {
+            if read_supply(&env) + amount > max_supply {
+                panic_with_error!(env, TokenError::SupplyCapExceeded);
+            }
+        }
+        increment_supply(&env, amount);
+        receive_balance(&env, to, amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+        spend_balance(&env, from, amount);
+        decrement_supply(&env, amount);
+    }
+
+    pub fn total_supply(env: Env) -> i128 {
+        read_supply(&env)
+    }
+
+    pub fn max_supply(env: Env) -> Option<i128> {
+        read_max_supply(&env)
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, id)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, from, spender)
+    }
+
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+        write_allowance(&env, from, spender, amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        from.require_auth();
+        spend_balance(&env, from, amount);
+        receive_balance(&env, to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        check_nonnegative_amount(amount);
+        spender.require_auth();
+        spend_allowance(&env, from.clone(), spender, amount);
+        spend_balance(&env, from, amount);
+        receive_balance(&env, to, amount);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        read_decimal(&env)
+    }
+
+    pub fn name(env: Env) -> String {
+        read_name(&env)
+    }
+
+    pub fn symbol(env: Env) -> String {
+        read_symbol(&env)
+    }
+}
@@ -0,0 +1,15 @@
+#![no_std]
+
+mod admin;
+mod allowance;
+mod balance;
+mod contract;
+mod error;
+mod metadata;
+mod storage_types;
+mod supply;
+
+#[cfg(test)]
+mod test;
+
+pub use contract::{Token, TokenClient};
@@ -0,0 +1,26 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::{Env, String};
+
+pub fn read_decimal(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Decimals).unwrap()
+}
+
+pub fn write_decimal(env: &Env, decimal: u32) {
+    env.storage().instance().set(&DataKey::Decimals, &decimal);
+}
+
+pub fn read_name(env: &Env) -> String {
+    env.storage().instance().get(&DataKey::Name).unwrap()
+}
+
+pub fn write_name(env: &Env, name: &String) {
+    env.storage().instance().set(&DataKey::Name, name);
+}
+
+pub fn read_symbol(env: &Env) -> String {
+    env.storage().instance().get(&DataKey::Symbol).unwrap()
+}
+
+pub fn write_symbol(env: &Env, symbol: &String) {
+    env.storage().instance().set(&DataKey::Symbol, symbol);
+}
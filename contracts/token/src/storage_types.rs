@@ -0,0 +1,21 @@
+use soroban_sdk::{contracttype, Address};
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    Balance(Address),
+    Allowance(AllowanceDataKey),
+    Supply,
+    MaxSupply,
+}
@@ -0,0 +1,33 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::Env;
+
+pub fn read_supply(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::Supply).unwrap_or(0)
+}
+
+fn write_supply(env: &Env, supply: i128) {
+    env.storage().instance().set(&DataKey::Supply, &supply);
+}
+
+pub fn increment_supply(env: &Env, amount: i128) {
+    let supply = read_supply(env);
+    write_supply(env, supply + amount);
+}
+
+pub fn decrement_supply(env: &Env, amount: i128) {
+    let supply = read_supply(env);
+    write_supply(env, supply - amount);
+}
+
+pub fn read_max_supply(env: &Env) -> Option<i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxSupply)
+        .unwrap_or(None)
+}
+
+pub fn write_max_supply(env: &Env, max_supply: Option<i128>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxSupply, &max_supply);
+}
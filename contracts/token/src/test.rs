@@ -0,0 +1,165 @@
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+#[test]
+fn test_mint_and_burn_track_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Test"),
+        &String::from_str(&env, "TST"),
+        &None,
+    );
+
+    client.mint(&holder, &1_000);
+    assert_eq!(client.total_supply(), 1_000);
+    assert_eq!(client.balance(&holder), 1_000);
+
+    client.burn(&holder, &400);
+    assert_eq!(client.total_supply(), 600);
+    assert_eq!(client.balance(&holder), 600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")] // SupplyCapExceeded error
+fn test_mint_rejects_amount_over_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Capped"),
+        &String::from_str(&env, "CAP"),
+        &Some(1_000),
+    );
+
+    client.mint(&holder, &1_001);
+}
+
+#[test]
+fn test_transfer_moves_balance_between_holders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Test"),
+        &String::from_str(&env, "TST"),
+        &None,
+    );
+
+    client.mint(&sender, &1_000);
+    client.transfer(&sender, &recipient, &400);
+
+    assert_eq!(client.balance(&sender), 600);
+    assert_eq!(client.balance(&recipient), 400);
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance")]
+fn test_transfer_rejects_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Test"),
+        &String::from_str(&env, "TST"),
+        &None,
+    );
+
+    client.mint(&sender, &100);
+    client.transfer(&sender, &recipient, &101);
+}
+
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Test"),
+        &String::from_str(&env, "TST"),
+        &None,
+    );
+
+    client.mint(&owner, &1_000);
+    client.approve(&owner, &spender, &300);
+    assert_eq!(client.allowance(&owner, &spender), 300);
+
+    client.transfer_from(&spender, &owner, &recipient, &200);
+
+    assert_eq!(client.balance(&owner), 800);
+    assert_eq!(client.balance(&recipient), 200);
+    assert_eq!(client.allowance(&owner, &spender), 100);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance")]
+fn test_transfer_from_rejects_insufficient_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, Token);
+    let client = TokenClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Test"),
+        &String::from_str(&env, "TST"),
+        &None,
+    );
+
+    client.mint(&owner, &1_000);
+    client.approve(&owner, &spender, &100);
+    client.transfer_from(&spender, &owner, &recipient, &101);
+}